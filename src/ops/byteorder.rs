@@ -0,0 +1,569 @@
+//! Unaligned, order-tagged integer and float wrappers.
+//!
+//! The types in this module store their value as a raw [`ToFromBytes::Bytes`](super::bytes::ToFromBytes::Bytes)
+//! array tagged with a [`ByteOrder`] marker, so they have alignment 1 and a fixed,
+//! known memory layout. This makes them suitable for describing structs whose
+//! layout matches a wire format with multi-byte fields at unaligned offsets and
+//! a fixed endianness, while still participating in this crate's numeric traits.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub};
+
+use crate::bounds::Bounded;
+use crate::cast::{FromPrimitive, NumCast, ToPrimitive};
+use crate::identities::{One, Zero};
+use crate::int::PrimInt;
+use crate::Num;
+
+use super::bytes::{Endianness, ToFromBytes};
+
+/// A byte order known at compile time, as a zero-sized marker type.
+///
+/// This is the compile-time counterpart of [`Endianness`]: it lets the
+/// [`U16`], [`U32`], ... wrapper types fix their byte order in the type
+/// system instead of carrying it as a runtime value.
+pub trait ByteOrder: Copy + Clone + fmt::Debug + Default + PartialEq + Eq + 'static {
+    /// The runtime [`Endianness`] this marker corresponds to.
+    const ENDIAN: Endianness;
+}
+
+/// Big-endian byte order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigEndian;
+
+impl ByteOrder for BigEndian {
+    const ENDIAN: Endianness = Endianness::Big;
+}
+
+/// Little-endian byte order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LittleEndian;
+
+impl ByteOrder for LittleEndian {
+    const ENDIAN: Endianness = Endianness::Little;
+}
+
+/// The target platform's native byte order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NativeEndian;
+
+impl ByteOrder for NativeEndian {
+    const ENDIAN: Endianness = Endianness::Native;
+}
+
+macro_rules! byteorder_common {
+    ($Name:ident, $Native:ty, $L:expr) => {
+        #[doc = concat!(
+            "A `", stringify!($Native), "` stored as a byte array in a compile-time-fixed order `O`."
+        )]
+        #[repr(transparent)]
+        #[derive(Clone, Copy)]
+        pub struct $Name<O> {
+            bytes: [u8; $L],
+            order: PhantomData<O>,
+        }
+
+        impl<O: ByteOrder> $Name<O> {
+            /// Create a new wrapper holding `value`, stored in order `O`.
+            #[inline]
+            pub fn new(value: $Native) -> Self {
+                $Name {
+                    bytes: value.to_bytes(O::ENDIAN),
+                    order: PhantomData,
+                }
+            }
+
+            /// Read the wrapped value back out in native byte order.
+            #[inline]
+            pub fn get(&self) -> $Native {
+                <$Native>::from_bytes(self.bytes, O::ENDIAN)
+            }
+
+            /// Overwrite the wrapped value, re-encoding it in order `O`.
+            #[inline]
+            pub fn set(&mut self, value: $Native) {
+                self.bytes = value.to_bytes(O::ENDIAN);
+            }
+        }
+
+        impl<O: ByteOrder> fmt::Debug for $Name<O> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($Name)).field(&self.get()).finish()
+            }
+        }
+
+        impl<O: ByteOrder> Default for $Name<O> {
+            #[inline]
+            fn default() -> Self {
+                Self::new(<$Native>::default())
+            }
+        }
+
+        impl<O: ByteOrder> From<$Name<O>> for $Native {
+            #[inline]
+            fn from(wrapped: $Name<O>) -> Self {
+                wrapped.get()
+            }
+        }
+
+        impl<O: ByteOrder> Zero for $Name<O> {
+            #[inline]
+            fn zero() -> Self {
+                Self::new(<$Native>::zero())
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.get().is_zero()
+            }
+        }
+
+        impl<O: ByteOrder> One for $Name<O> {
+            #[inline]
+            fn one() -> Self {
+                Self::new(<$Native>::one())
+            }
+        }
+
+        impl<O: ByteOrder> Add for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self::new(self.get() + rhs.get())
+            }
+        }
+
+        impl<O: ByteOrder> Sub for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self::new(self.get() - rhs.get())
+            }
+        }
+
+        impl<O: ByteOrder> Mul for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self::new(self.get() * rhs.get())
+            }
+        }
+
+        impl<O: ByteOrder> Div for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                Self::new(self.get() / rhs.get())
+            }
+        }
+
+        impl<O: ByteOrder> Rem for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn rem(self, rhs: Self) -> Self {
+                Self::new(self.get() % rhs.get())
+            }
+        }
+
+        impl<O: ByteOrder> Num for $Name<O> {
+            type FromStrRadixErr = <$Native as Num>::FromStrRadixErr;
+
+            #[inline]
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                <$Native>::from_str_radix(str, radix).map(Self::new)
+            }
+        }
+
+        impl<O: ByteOrder> ToPrimitive for $Name<O> {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                self.get().to_i64()
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                self.get().to_u64()
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                self.get().to_f64()
+            }
+        }
+
+        impl<O: ByteOrder> FromPrimitive for $Name<O> {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                <$Native as FromPrimitive>::from_i64(n).map(Self::new)
+            }
+
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                <$Native as FromPrimitive>::from_u64(n).map(Self::new)
+            }
+
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                <$Native as FromPrimitive>::from_f64(n).map(Self::new)
+            }
+        }
+
+        impl<O: ByteOrder> NumCast for $Name<O> {
+            #[inline]
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                <$Native as NumCast>::from(n).map(Self::new)
+            }
+        }
+    };
+}
+
+macro_rules! byteorder_int_wrapper {
+    ($Name:ident, $Native:ty, $L:expr) => {
+        byteorder_common!($Name, $Native, $L);
+
+        impl<O: ByteOrder> PartialEq for $Name<O> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.bytes == other.bytes
+            }
+        }
+
+        impl<O: ByteOrder> Eq for $Name<O> {}
+
+        impl<O: ByteOrder> core::hash::Hash for $Name<O> {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.bytes.hash(state);
+            }
+        }
+
+        impl<O: ByteOrder> Ord for $Name<O> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl<O: ByteOrder> PartialOrd for $Name<O> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<O: ByteOrder> Bounded for $Name<O> {
+            #[inline]
+            fn min_value() -> Self {
+                Self::new(<$Native>::min_value())
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::new(<$Native>::max_value())
+            }
+        }
+
+        impl<O: ByteOrder> Not for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self {
+                Self::new(!self.get())
+            }
+        }
+
+        impl<O: ByteOrder> BitAnd for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, rhs: Self) -> Self {
+                Self::new(self.get() & rhs.get())
+            }
+        }
+
+        impl<O: ByteOrder> BitOr for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self {
+                Self::new(self.get() | rhs.get())
+            }
+        }
+
+        impl<O: ByteOrder> BitXor for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn bitxor(self, rhs: Self) -> Self {
+                Self::new(self.get() ^ rhs.get())
+            }
+        }
+
+        impl<O: ByteOrder> Shl<usize> for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn shl(self, rhs: usize) -> Self {
+                Self::new(self.get() << rhs)
+            }
+        }
+
+        impl<O: ByteOrder> Shr<usize> for $Name<O> {
+            type Output = Self;
+            #[inline]
+            fn shr(self, rhs: usize) -> Self {
+                Self::new(self.get() >> rhs)
+            }
+        }
+
+        impl<O: ByteOrder> PrimInt for $Name<O> {
+            #[inline]
+            fn count_ones(self) -> u32 {
+                self.get().count_ones()
+            }
+
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                self.get().leading_zeros()
+            }
+
+            #[inline]
+            fn trailing_zeros(self) -> u32 {
+                self.get().trailing_zeros()
+            }
+
+            #[inline]
+            fn rotate_left(self, n: u32) -> Self {
+                Self::new(self.get().rotate_left(n))
+            }
+
+            #[inline]
+            fn rotate_right(self, n: u32) -> Self {
+                Self::new(self.get().rotate_right(n))
+            }
+
+            #[inline]
+            fn signed_shl(self, n: u32) -> Self {
+                Self::new(self.get().signed_shl(n))
+            }
+
+            #[inline]
+            fn signed_shr(self, n: u32) -> Self {
+                Self::new(self.get().signed_shr(n))
+            }
+
+            #[inline]
+            fn unsigned_shl(self, n: u32) -> Self {
+                Self::new(self.get().unsigned_shl(n))
+            }
+
+            #[inline]
+            fn unsigned_shr(self, n: u32) -> Self {
+                Self::new(self.get().unsigned_shr(n))
+            }
+
+            #[inline]
+            fn swap_bytes(self) -> Self {
+                Self::new(self.get().swap_bytes())
+            }
+
+            #[inline]
+            fn from_be(x: Self) -> Self {
+                Self::new(<$Native>::from_be(x.get()))
+            }
+
+            #[inline]
+            fn to_be(self) -> Self {
+                Self::new(self.get().to_be())
+            }
+
+            #[inline]
+            fn from_le(x: Self) -> Self {
+                Self::new(<$Native>::from_le(x.get()))
+            }
+
+            #[inline]
+            fn to_le(self) -> Self {
+                Self::new(self.get().to_le())
+            }
+
+            #[inline]
+            fn pow(self, exp: u32) -> Self {
+                Self::new(self.get().pow(exp))
+            }
+        }
+    };
+}
+
+macro_rules! byteorder_float_wrapper {
+    ($Name:ident, $Native:ty, $L:expr) => {
+        byteorder_common!($Name, $Native, $L);
+
+        // Like `f32`/`f64` themselves, this type has no `Eq` impl: `get()` is used for
+        // equality (so `-0.0 == 0.0`, matching `PartialOrd` below), and NaN is never
+        // equal to itself, which rules out a lawful `Eq`.
+        impl<O: ByteOrder> PartialEq for $Name<O> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl<O: ByteOrder> PartialOrd for $Name<O> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                self.get().partial_cmp(&other.get())
+            }
+        }
+    };
+}
+
+byteorder_int_wrapper!(U16, u16, 2);
+byteorder_int_wrapper!(I16, i16, 2);
+byteorder_int_wrapper!(U32, u32, 4);
+byteorder_int_wrapper!(I32, i32, 4);
+byteorder_int_wrapper!(U64, u64, 8);
+byteorder_int_wrapper!(I64, i64, 8);
+
+#[cfg(has_i128)]
+byteorder_int_wrapper!(U128, u128, 16);
+#[cfg(has_i128)]
+byteorder_int_wrapper!(I128, i128, 16);
+
+byteorder_float_wrapper!(F32, f32, 4);
+byteorder_float_wrapper!(F64, f64, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn u16_byte_order() {
+        assert_eq!(U16::<BigEndian>::new(0x1234).bytes, [0x12, 0x34]);
+        assert_eq!(U16::<LittleEndian>::new(0x1234).bytes, [0x34, 0x12]);
+        assert_eq!(U16::<BigEndian>::new(0x1234).get(), 0x1234);
+        assert_eq!(U16::<LittleEndian>::new(0x1234).get(), 0x1234);
+    }
+
+    #[test]
+    fn i16_byte_order() {
+        assert_eq!(I16::<BigEndian>::new(-2).bytes, [0xff, 0xfe]);
+        assert_eq!(I16::<LittleEndian>::new(-2).bytes, [0xfe, 0xff]);
+        assert_eq!(I16::<BigEndian>::new(-2).get(), -2);
+        assert_eq!(I16::<LittleEndian>::new(-2).get(), -2);
+    }
+
+    #[test]
+    fn u32_byte_order() {
+        assert_eq!(
+            U32::<BigEndian>::new(0x12345678).bytes,
+            [0x12, 0x34, 0x56, 0x78]
+        );
+        assert_eq!(
+            U32::<LittleEndian>::new(0x12345678).bytes,
+            [0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(U32::<BigEndian>::new(0x12345678).get(), 0x12345678);
+        assert_eq!(U32::<LittleEndian>::new(0x12345678).get(), 0x12345678);
+    }
+
+    #[test]
+    fn i32_byte_order() {
+        assert_eq!(I32::<BigEndian>::new(-2).bytes, [0xff, 0xff, 0xff, 0xfe]);
+        assert_eq!(I32::<LittleEndian>::new(-2).bytes, [0xfe, 0xff, 0xff, 0xff]);
+        assert_eq!(I32::<BigEndian>::new(-2).get(), -2);
+        assert_eq!(I32::<LittleEndian>::new(-2).get(), -2);
+    }
+
+    #[test]
+    fn u64_byte_order() {
+        let value = 0x0102030405060708u64;
+        assert_eq!(
+            U64::<BigEndian>::new(value).bytes,
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+        );
+        assert_eq!(
+            U64::<LittleEndian>::new(value).bytes,
+            [0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01]
+        );
+        assert_eq!(U64::<BigEndian>::new(value).get(), value);
+        assert_eq!(U64::<LittleEndian>::new(value).get(), value);
+    }
+
+    #[test]
+    fn i64_byte_order() {
+        let value = -2i64;
+        assert_eq!(I64::<BigEndian>::new(value).get(), value);
+        assert_eq!(I64::<LittleEndian>::new(value).get(), value);
+        assert_eq!(
+            I64::<BigEndian>::new(value).bytes,
+            value.to_be_bytes()
+        );
+        assert_eq!(
+            I64::<LittleEndian>::new(value).bytes,
+            value.to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn f32_byte_order() {
+        assert_eq!(F32::<BigEndian>::new(1.0).bytes, 1.0f32.to_be_bytes());
+        assert_eq!(F32::<LittleEndian>::new(1.0).bytes, 1.0f32.to_le_bytes());
+        assert_eq!(F32::<BigEndian>::new(1.0).get(), 1.0);
+        assert_eq!(F32::<LittleEndian>::new(1.0).get(), 1.0);
+    }
+
+    #[test]
+    fn f64_byte_order() {
+        assert_eq!(F64::<BigEndian>::new(1.0).bytes, 1.0f64.to_be_bytes());
+        assert_eq!(F64::<LittleEndian>::new(1.0).bytes, 1.0f64.to_le_bytes());
+        assert_eq!(F64::<BigEndian>::new(1.0).get(), 1.0);
+        assert_eq!(F64::<LittleEndian>::new(1.0).get(), 1.0);
+    }
+
+    // `F32`/`F64` compare by value (via `get()`), not by raw bytes, so `0.0` and
+    // `-0.0` (distinct bit patterns) must compare equal, while a NaN (identical
+    // bit pattern to itself) must not. A byte-based `PartialEq` would get both
+    // of these backwards.
+    #[test]
+    fn f32_value_equality_not_byte_equality() {
+        assert_eq!(F32::<LittleEndian>::new(0.0), F32::<LittleEndian>::new(-0.0));
+        assert_ne!(
+            F32::<LittleEndian>::new(0.0).bytes,
+            F32::<LittleEndian>::new(-0.0).bytes
+        );
+        assert_ne!(
+            F32::<LittleEndian>::new(f32::NAN),
+            F32::<LittleEndian>::new(f32::NAN)
+        );
+    }
+
+    #[test]
+    fn f64_value_equality_not_byte_equality() {
+        assert_eq!(F64::<LittleEndian>::new(0.0), F64::<LittleEndian>::new(-0.0));
+        assert_ne!(
+            F64::<LittleEndian>::new(0.0).bytes,
+            F64::<LittleEndian>::new(-0.0).bytes
+        );
+        assert_ne!(
+            F64::<LittleEndian>::new(f64::NAN),
+            F64::<LittleEndian>::new(f64::NAN)
+        );
+    }
+
+    #[cfg(has_i128)]
+    #[test]
+    fn u128_byte_order() {
+        let value = 0x0102030405060708090a0b0c0d0e0f10u128;
+        assert_eq!(U128::<BigEndian>::new(value).bytes, value.to_be_bytes());
+        assert_eq!(U128::<LittleEndian>::new(value).bytes, value.to_le_bytes());
+        assert_eq!(U128::<BigEndian>::new(value).get(), value);
+        assert_eq!(U128::<LittleEndian>::new(value).get(), value);
+    }
+
+    #[cfg(has_i128)]
+    #[test]
+    fn i128_byte_order() {
+        let value = -2i128;
+        assert_eq!(I128::<BigEndian>::new(value).bytes, value.to_be_bytes());
+        assert_eq!(I128::<LittleEndian>::new(value).bytes, value.to_le_bytes());
+        assert_eq!(I128::<BigEndian>::new(value).get(), value);
+        assert_eq!(I128::<LittleEndian>::new(value).get(), value);
+    }
+}
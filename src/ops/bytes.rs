@@ -2,8 +2,25 @@ use core::borrow::{Borrow, BorrowMut};
 use core::cmp::{Eq, Ord, PartialEq, PartialOrd};
 use core::fmt::Debug;
 use core::hash::Hash;
+use core::mem::size_of;
+#[cfg(feature = "use-unsafe")]
 use core::mem::transmute;
 
+/// A byte order, to be chosen at runtime rather than baked into the method name.
+///
+/// This is useful when code (for example a parser) only learns which byte order
+/// to use after inspecting some input, and wants to forward a single value
+/// rather than branching itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Big-endian byte order, as returned by [`to_be_bytes`](ToFromBytes::to_be_bytes).
+    Big,
+    /// Little-endian byte order, as returned by [`to_le_bytes`](ToFromBytes::to_le_bytes).
+    Little,
+    /// The target platform's native byte order, as returned by [`to_ne_bytes`](ToFromBytes::to_ne_bytes).
+    Native,
+}
+
 pub trait ToFromBytes {
     type Bytes: Debug
         + AsRef<[u8]>
@@ -108,6 +125,157 @@ pub trait ToFromBytes {
     /// assert_eq!(value, 0x12345678);
     /// ```
     fn from_ne_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Return the memory representation of this number as a byte array in the given byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::{Endianness, ToFromBytes};
+    ///
+    /// let bytes = 0x12345678u32.to_bytes(Endianness::Big);
+    /// assert_eq!(bytes, [0x12, 0x34, 0x56, 0x78]);
+    ///
+    /// let bytes = 0x12345678u32.to_bytes(Endianness::Little);
+    /// assert_eq!(bytes, [0x78, 0x56, 0x34, 0x12]);
+    ///
+    /// let bytes = 0x12345678u32.to_bytes(Endianness::Native);
+    /// assert_eq!(bytes, 0x12345678u32.to_ne_bytes());
+    /// ```
+    #[inline]
+    fn to_bytes(self, endian: Endianness) -> Self::Bytes
+    where
+        Self: Sized,
+    {
+        match endian {
+            Endianness::Big => self.to_be_bytes(),
+            Endianness::Little => self.to_le_bytes(),
+            Endianness::Native => self.to_ne_bytes(),
+        }
+    }
+
+    /// Create a number from its representation as a byte array in the given byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::{Endianness, ToFromBytes};
+    ///
+    /// let value = u32::from_bytes([0x12, 0x34, 0x56, 0x78], Endianness::Big);
+    /// assert_eq!(value, 0x12345678);
+    ///
+    /// let value = u32::from_bytes([0x78, 0x56, 0x34, 0x12], Endianness::Little);
+    /// assert_eq!(value, 0x12345678);
+    ///
+    /// let value = u32::from_bytes(0x12345678u32.to_ne_bytes(), Endianness::Native);
+    /// assert_eq!(value, 0x12345678);
+    /// ```
+    #[inline]
+    fn from_bytes(bytes: Self::Bytes, endian: Endianness) -> Self
+    where
+        Self: Sized,
+    {
+        match endian {
+            Endianness::Big => Self::from_be_bytes(bytes),
+            Endianness::Little => Self::from_le_bytes(bytes),
+            Endianness::Native => Self::from_ne_bytes(bytes),
+        }
+    }
+
+    /// Create a number from a big-endian byte slice, or `None` if `slice` isn't exactly
+    /// [`size_of::<Self::Bytes>()`](Self::Bytes) bytes long.
+    ///
+    /// This length check assumes `Self::Bytes` is a plain, fixed-size byte buffer (true
+    /// of every `ToFromBytes` impl in this crate, all of which use `[u8; N]`); a future
+    /// impl whose `Bytes` is some other container would need its own length check here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ToFromBytes;
+    ///
+    /// assert_eq!(u32::from_be_slice(&[0x12, 0x34, 0x56, 0x78]), Some(0x12345678));
+    /// assert_eq!(u32::from_be_slice(&[0x12, 0x34]), None);
+    /// ```
+    #[inline]
+    fn from_be_slice(slice: &[u8]) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if slice.len() != size_of::<Self::Bytes>() {
+            return None;
+        }
+        let mut bytes = Self::Bytes::default();
+        bytes.borrow_mut().copy_from_slice(slice);
+        Some(Self::from_be_bytes(bytes))
+    }
+
+    /// Create a number from a little-endian byte slice, or `None` if `slice` isn't exactly
+    /// [`size_of::<Self::Bytes>()`](Self::Bytes) bytes long.
+    ///
+    /// This length check assumes `Self::Bytes` is a plain, fixed-size byte buffer (true
+    /// of every `ToFromBytes` impl in this crate, all of which use `[u8; N]`); a future
+    /// impl whose `Bytes` is some other container would need its own length check here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ToFromBytes;
+    ///
+    /// assert_eq!(u32::from_le_slice(&[0x78, 0x56, 0x34, 0x12]), Some(0x12345678));
+    /// assert_eq!(u32::from_le_slice(&[0x12, 0x34]), None);
+    /// ```
+    #[inline]
+    fn from_le_slice(slice: &[u8]) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if slice.len() != size_of::<Self::Bytes>() {
+            return None;
+        }
+        let mut bytes = Self::Bytes::default();
+        bytes.borrow_mut().copy_from_slice(slice);
+        Some(Self::from_le_bytes(bytes))
+    }
+
+    /// Create a number from a native-endian byte slice, or `None` if `slice` isn't exactly
+    /// [`size_of::<Self::Bytes>()`](Self::Bytes) bytes long.
+    ///
+    /// As the target platform's native endianness is used,
+    /// portable code likely wants to use [`from_be_slice`] or [`from_le_slice`], as appropriate instead.
+    ///
+    /// This length check assumes `Self::Bytes` is a plain, fixed-size byte buffer (true
+    /// of every `ToFromBytes` impl in this crate, all of which use `[u8; N]`); a future
+    /// impl whose `Bytes` is some other container would need its own length check here.
+    ///
+    /// [`from_be_slice`]: #method.from_be_slice
+    /// [`from_le_slice`]: #method.from_le_slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ToFromBytes;
+    ///
+    /// let bytes = if cfg!(target_endian = "big") {
+    ///     [0x12, 0x34, 0x56, 0x78]
+    /// } else {
+    ///     [0x78, 0x56, 0x34, 0x12]
+    /// };
+    /// assert_eq!(u32::from_ne_slice(&bytes), Some(0x12345678));
+    /// assert_eq!(u32::from_ne_slice(&[0x12, 0x34]), None);
+    /// ```
+    #[inline]
+    fn from_ne_slice(slice: &[u8]) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if slice.len() != size_of::<Self::Bytes>() {
+            return None;
+        }
+        let mut bytes = Self::Bytes::default();
+        bytes.borrow_mut().copy_from_slice(slice);
+        Some(Self::from_ne_bytes(bytes))
+    }
 }
 
 macro_rules! float_to_from_bytes_impl {
@@ -161,11 +329,18 @@ macro_rules! float_to_from_bytes_impl {
                 <$I>::from_ne_bytes(self.to_ne_bytes()).to_le_bytes()
             }
 
+            #[cfg(feature = "use-unsafe")]
             #[inline]
             fn to_ne_bytes(self) -> Self::Bytes {
                 unsafe { transmute(self) }
             }
 
+            #[cfg(not(feature = "use-unsafe"))]
+            #[inline]
+            fn to_ne_bytes(self) -> Self::Bytes {
+                <$T>::to_bits(self).to_ne_bytes()
+            }
+
             #[inline]
             fn from_be_bytes(bytes: Self::Bytes) -> Self {
                 Self::from_ne_bytes(<$I>::from_be_bytes(bytes).to_ne_bytes())
@@ -176,10 +351,17 @@ macro_rules! float_to_from_bytes_impl {
                 Self::from_ne_bytes(<$I>::from_le_bytes(bytes).to_ne_bytes())
             }
 
+            #[cfg(feature = "use-unsafe")]
             #[inline]
             fn from_ne_bytes(bytes: Self::Bytes) -> Self {
                 unsafe { transmute(bytes) }
             }
+
+            #[cfg(not(feature = "use-unsafe"))]
+            #[inline]
+            fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+                <$T>::from_bits(<$I>::from_ne_bytes(bytes))
+            }
         }
     };
 }
@@ -235,11 +417,25 @@ macro_rules! int_to_from_bytes_impl {
                 <$T>::to_ne_bytes(<$T>::to_le(self))
             }
 
+            #[cfg(feature = "use-unsafe")]
             #[inline]
             fn to_ne_bytes(self) -> Self::Bytes {
                 unsafe { transmute(self) }
             }
 
+            #[cfg(not(feature = "use-unsafe"))]
+            #[inline]
+            fn to_ne_bytes(self) -> Self::Bytes {
+                let mut bytes = [0u8; $L];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = (self >> (8 * i)) as u8;
+                }
+                if cfg!(target_endian = "big") {
+                    bytes.reverse();
+                }
+                bytes
+            }
+
             #[inline]
             fn from_be_bytes(bytes: Self::Bytes) -> Self {
                 Self::from_be(Self::from_ne_bytes(bytes))
@@ -250,10 +446,24 @@ macro_rules! int_to_from_bytes_impl {
                 Self::from_le(Self::from_ne_bytes(bytes))
             }
 
+            #[cfg(feature = "use-unsafe")]
             #[inline]
             fn from_ne_bytes(bytes: Self::Bytes) -> Self {
                 unsafe { transmute(bytes) }
             }
+
+            #[cfg(not(feature = "use-unsafe"))]
+            #[inline]
+            fn from_ne_bytes(mut bytes: Self::Bytes) -> Self {
+                if cfg!(target_endian = "big") {
+                    bytes.reverse();
+                }
+                let mut value: $T = 0;
+                for (i, byte) in bytes.iter().enumerate() {
+                    value |= (*byte as $T) << (8 * i);
+                }
+                value
+            }
         }
     };
 }
@@ -277,3 +487,173 @@ int_to_from_bytes_impl!(i128, 16);
 
 float_to_from_bytes_impl!(f32, u32, 4);
 float_to_from_bytes_impl!(f64, u64, 8);
+
+#[cfg(feature = "std")]
+mod io_ext {
+    use super::ToFromBytes;
+    use std::io::{self, Read, Write};
+
+    /// Extends [`Read`] with methods for reading numbers directly off a stream.
+    pub trait ReadBytesExt: Read {
+        /// Read a `T` in big-endian byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use num_traits::ReadBytesExt;
+        /// use std::io::Cursor;
+        ///
+        /// let mut cursor = Cursor::new([0x12, 0x34, 0x56, 0x78]);
+        /// let value: u32 = cursor.read_be().unwrap();
+        /// assert_eq!(value, 0x12345678);
+        /// ```
+        #[inline]
+        fn read_be<T: ToFromBytes>(&mut self) -> io::Result<T> {
+            let mut bytes = T::Bytes::default();
+            self.read_exact(bytes.as_mut())?;
+            Ok(T::from_be_bytes(bytes))
+        }
+
+        /// Read a `T` in little-endian byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use num_traits::ReadBytesExt;
+        /// use std::io::Cursor;
+        ///
+        /// let mut cursor = Cursor::new([0x78, 0x56, 0x34, 0x12]);
+        /// let value: u32 = cursor.read_le().unwrap();
+        /// assert_eq!(value, 0x12345678);
+        /// ```
+        #[inline]
+        fn read_le<T: ToFromBytes>(&mut self) -> io::Result<T> {
+            let mut bytes = T::Bytes::default();
+            self.read_exact(bytes.as_mut())?;
+            Ok(T::from_le_bytes(bytes))
+        }
+
+        /// Read a `T` in the target platform's native byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use num_traits::ReadBytesExt;
+        /// use std::io::Cursor;
+        ///
+        /// let mut cursor = Cursor::new(0x12345678u32.to_ne_bytes());
+        /// let value: u32 = cursor.read_ne().unwrap();
+        /// assert_eq!(value, 0x12345678);
+        /// ```
+        #[inline]
+        fn read_ne<T: ToFromBytes>(&mut self) -> io::Result<T> {
+            let mut bytes = T::Bytes::default();
+            self.read_exact(bytes.as_mut())?;
+            Ok(T::from_ne_bytes(bytes))
+        }
+    }
+
+    /// Extends [`Write`] with methods for writing numbers directly to a stream.
+    pub trait WriteBytesExt: Write {
+        /// Write a `T` in big-endian byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use num_traits::WriteBytesExt;
+        ///
+        /// let mut buf = Vec::new();
+        /// buf.write_be(0x12345678u32).unwrap();
+        /// assert_eq!(buf, [0x12, 0x34, 0x56, 0x78]);
+        /// ```
+        #[inline]
+        fn write_be<T: ToFromBytes>(&mut self, value: T) -> io::Result<()> {
+            self.write_all(value.to_be_bytes().as_ref())
+        }
+
+        /// Write a `T` in little-endian byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use num_traits::WriteBytesExt;
+        ///
+        /// let mut buf = Vec::new();
+        /// buf.write_le(0x12345678u32).unwrap();
+        /// assert_eq!(buf, [0x78, 0x56, 0x34, 0x12]);
+        /// ```
+        #[inline]
+        fn write_le<T: ToFromBytes>(&mut self, value: T) -> io::Result<()> {
+            self.write_all(value.to_le_bytes().as_ref())
+        }
+
+        /// Write a `T` in the target platform's native byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use num_traits::WriteBytesExt;
+        ///
+        /// let mut buf = Vec::new();
+        /// buf.write_ne(0x12345678u32).unwrap();
+        /// assert_eq!(buf, 0x12345678u32.to_ne_bytes());
+        /// ```
+        #[inline]
+        fn write_ne<T: ToFromBytes>(&mut self, value: T) -> io::Result<()> {
+            self.write_all(value.to_ne_bytes().as_ref())
+        }
+    }
+
+    impl<R: Read + ?Sized> ReadBytesExt for R {}
+    impl<W: Write + ?Sized> WriteBytesExt for W {}
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::io::Cursor;
+
+        #[test]
+        fn round_trip_be() {
+            let mut buf = Vec::new();
+            buf.write_be(0x1234_5678u32).unwrap();
+            let mut cursor = Cursor::new(buf);
+            let value: u32 = cursor.read_be().unwrap();
+            assert_eq!(value, 0x1234_5678);
+        }
+
+        #[test]
+        fn round_trip_le() {
+            let mut buf = Vec::new();
+            buf.write_le(0x1234_5678u32).unwrap();
+            let mut cursor = Cursor::new(buf);
+            let value: u32 = cursor.read_le().unwrap();
+            assert_eq!(value, 0x1234_5678);
+        }
+
+        #[test]
+        fn round_trip_ne() {
+            let mut buf = Vec::new();
+            buf.write_ne(0x1234_5678u32).unwrap();
+            let mut cursor = Cursor::new(buf);
+            let value: u32 = cursor.read_ne().unwrap();
+            assert_eq!(value, 0x1234_5678);
+        }
+
+        #[test]
+        fn write_be_matches_big_endian_bytes() {
+            let mut buf = Vec::new();
+            buf.write_be(0x1234_5678u32).unwrap();
+            assert_eq!(buf, [0x12, 0x34, 0x56, 0x78]);
+        }
+
+        #[test]
+        fn write_le_matches_little_endian_bytes() {
+            let mut buf = Vec::new();
+            buf.write_le(0x1234_5678u32).unwrap();
+            assert_eq!(buf, [0x78, 0x56, 0x34, 0x12]);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use io_ext::{ReadBytesExt, WriteBytesExt};
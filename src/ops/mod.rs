@@ -0,0 +1,2 @@
+pub mod byteorder;
+pub mod bytes;